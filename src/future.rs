@@ -0,0 +1,58 @@
+//! `core::future::Future` adapter for `nb::Result`
+//!
+//! This lets HAL authors bridge a non-blocking `nb`-based API into `async fn` code without
+//! depending on any particular executor.
+
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+
+/// Turns a non-blocking expression into a future
+///
+/// `f` is called on every poll. `Ok(t)` and `Err(nb::Error::Other(e))` complete the future;
+/// `Err(nb::Error::WouldBlock)` re-arms the waker (so the executor polls again instead of
+/// blocking) and yields `Poll::Pending`.
+///
+/// # Input
+///
+/// A closure `f` that evaluates to `nb::Result<T, E>`
+///
+/// # Output
+///
+/// - `Ok(t)` once `f` evaluates to `Ok(t)`
+/// - `Err(e)` once `f` evaluates to `Err(nb::Error::Other(e))`
+pub fn poll_fn<T, E, F>(f: F) -> PollFn<F>
+where
+    F: FnMut() -> super::Result<T, E>,
+{
+    PollFn { f }
+}
+
+/// A future that wraps a non-blocking `FnMut`
+///
+/// This `struct` is created by the [`poll_fn`] function.
+///
+/// [`poll_fn`]: fn.poll_fn.html
+pub struct PollFn<F> {
+    f: F,
+}
+
+impl<F> Unpin for PollFn<F> {}
+
+impl<T, E, F> Future for PollFn<F>
+where
+    F: FnMut() -> super::Result<T, E>,
+{
+    type Output = Result<T, E>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        match (self.f)() {
+            Ok(x) => Poll::Ready(Ok(x)),
+            Err(super::Error::Other(e)) => Poll::Ready(Err(e)),
+            Err(super::Error::WouldBlock) => {
+                cx.waker().wake_by_ref();
+                Poll::Pending
+            }
+        }
+    }
+}
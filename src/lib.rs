@@ -72,14 +72,18 @@
 //!
 //! Once your API uses [`nb::Result`](type.Result.html) you can leverage the
 //! [`block!`], [`try_nb!`] and [`await!`] macros to adapt it for blocking
-//! operation, or for non-blocking operation with `futures` or `await`.
+//! operation, or for non-blocking operation with `futures` or `await`. For `core::future::Future`
+//! based `async`/`await`, adapt a single operation with [`future::poll_fn`] or a sequence of
+//! repeated reads with [`stream::poll_fn`].
 //!
-//! **NOTE** Currently, both `try_nb!` and `await!` are feature gated behind the `unstable` Cargo
-//! feature.
+//! **NOTE** Currently, `try_nb!`, `await!`, [`future::poll_fn`] and [`stream::poll_fn`] are
+//! feature gated behind the `unstable` Cargo feature.
 //!
 //! [`block!`]: macro.block.html
 //! [`try_nb!`]: macro.try_nb.html
 //! [`await!`]: macro.await.html
+//! [`future::poll_fn`]: future/fn.poll_fn.html
+//! [`stream::poll_fn`]: stream/fn.poll_fn.html
 //!
 //! # Examples
 //!
@@ -359,6 +363,18 @@
 
 use core::fmt;
 
+/// `core::future::Future` adapter
+///
+/// **NOTE** This module is feature gated behind the `unstable` Cargo feature.
+#[cfg(feature = "unstable")]
+pub mod future;
+
+/// `futures_core::Stream` adapter
+///
+/// **NOTE** This module is feature gated behind the `unstable` Cargo feature.
+#[cfg(feature = "unstable")]
+pub mod stream;
+
 /// A non-blocking result
 pub type Result<T, E> = ::core::result::Result<T, Error<E>>;
 
@@ -403,6 +419,116 @@ impl<E> From<E> for Error<E> {
     }
 }
 
+#[cfg(feature = "std")]
+extern crate std;
+
+#[cfg(feature = "std")]
+impl<E> std::fmt::Display for Error<E>
+where
+    E: std::fmt::Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match *self {
+            Error::Other(ref e) => std::fmt::Display::fmt(e, f),
+            Error::WouldBlock => f.write_str("would block"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<E> std::error::Error for Error<E>
+where
+    E: std::error::Error,
+{
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match *self {
+            Error::Other(ref e) => Some(e),
+            Error::WouldBlock => None,
+        }
+    }
+}
+
+/// Maps `Error::WouldBlock` to `std::io::ErrorKind::WouldBlock` and unwraps `Error::Other`
+///
+/// **NOTE** This impl is feature gated behind the `std` Cargo feature.
+#[cfg(feature = "std")]
+impl From<Error<std::io::Error>> for std::io::Error {
+    fn from(error: Error<std::io::Error>) -> std::io::Error {
+        match error {
+            Error::Other(e) => e,
+            Error::WouldBlock => std::io::Error::from(std::io::ErrorKind::WouldBlock),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error<std::io::Error> {
+    /// Converts a `std::io::Error` into an `Error<std::io::Error>`, mapping
+    /// `ErrorKind::WouldBlock` to [`Error::WouldBlock`](enum.Error.html#variant.WouldBlock) and
+    /// everything else to `Error::Other`
+    ///
+    /// This is the inverse of `From<Error<std::io::Error>> for std::io::Error` above. It's a
+    /// named constructor rather than a `From<std::io::Error>` impl because that impl would
+    /// overlap with the blanket `impl<E> From<E> for Error<E>` for this exact pair of types.
+    ///
+    /// **NOTE** This method is feature gated behind the `std` Cargo feature.
+    pub fn from_io_error(error: std::io::Error) -> Self {
+        if error.kind() == std::io::ErrorKind::WouldBlock {
+            Error::WouldBlock
+        } else {
+            Error::Other(error)
+        }
+    }
+}
+
+/// Converts an `nb::Result` into a `core::task::Poll`
+///
+/// This is the minimal, dependency-free primitive for hand-writing a `Future::poll` (or
+/// `Stream::poll_next`) body over an `nb`-based API; it supersedes the futures-0.1-specific
+/// [`try_nb!`].
+///
+/// **NOTE** This function is feature gated behind the `unstable` Cargo feature.
+///
+/// # Output
+///
+/// - `Poll::Ready(Ok(t))` if `result` is `Ok(t)`
+/// - `Poll::Ready(Err(e))` if `result` is `Err(Error::Other(e))`
+/// - `Poll::Pending` if `result` is `Err(Error::WouldBlock)`
+///
+/// [`try_nb!`]: macro.try_nb.html
+#[cfg(feature = "unstable")]
+pub fn into_poll<T, E>(
+    result: Result<T, E>,
+) -> core::task::Poll<core::result::Result<T, E>> {
+    match result {
+        Ok(x) => core::task::Poll::Ready(Ok(x)),
+        Err(Error::Other(e)) => core::task::Poll::Ready(Err(e)),
+        Err(Error::WouldBlock) => core::task::Poll::Pending,
+    }
+}
+
+/// Converts a `core::task::Poll` into an `nb::Result`
+///
+/// This is the inverse of [`into_poll`](fn.into_poll.html).
+///
+/// **NOTE** This function is feature gated behind the `unstable` Cargo feature.
+///
+/// # Output
+///
+/// - `Ok(t)` if `poll` is `Poll::Ready(Ok(t))`
+/// - `Err(Error::Other(e))` if `poll` is `Poll::Ready(Err(e))`
+/// - `Err(Error::WouldBlock)` if `poll` is `Poll::Pending`
+#[cfg(feature = "unstable")]
+pub fn from_poll<T, E>(
+    poll: core::task::Poll<core::result::Result<T, E>>,
+) -> Result<T, E> {
+    match poll {
+        core::task::Poll::Ready(Ok(x)) => Ok(x),
+        core::task::Poll::Ready(Err(e)) => Err(Error::Other(e)),
+        core::task::Poll::Pending => Err(Error::WouldBlock),
+    }
+}
+
 /// Await operation (*won't work until the language gains support for
 /// generators*)
 ///
@@ -472,6 +598,41 @@ macro_rules! block {
     }
 }
 
+/// Turns the non-blocking expression `$e` into a blocking operation, evaluating `$relax` on
+/// every `Error::WouldBlock`.
+///
+/// This is [`block!`] with a hook for the caller to idle the CPU (e.g. `cortex_m::asm::wfi()`
+/// or `core::hint::spin_loop()`) between polls instead of busy waiting, which is the idiomatic
+/// way to save power while blocking on real microcontrollers.
+///
+/// # Input
+///
+/// An expression `$relax` that's evaluated on every `Error::WouldBlock`
+/// An expression `$e` that evaluates to `nb::Result<T, E>`
+///
+/// # Output
+///
+/// - `Ok(t)` if `$e` evaluates to `Ok(t)`
+/// - `Err(e)` if `$e` evaluates to `Err(nb::Error::Other(e))`
+///
+/// [`block!`]: macro.block.html
+#[macro_export]
+macro_rules! block_with {
+    ($relax:expr, $e:expr) => {
+        loop {
+            #[allow(unreachable_patterns)]
+            match $e {
+                Err($crate::Error::Other(e)) => {
+                    #[allow(unreachable_code)]
+                    break Err(e)
+                },
+                Err($crate::Error::WouldBlock) => { $relax; },
+                Ok(x) => break Ok(x),
+            }
+        }
+    }
+}
+
 /// Turns the non-blocking expression `$e` into a blocking operation for as long
 /// as the given expression evaluates to true.
 ///
@@ -511,6 +672,55 @@ macro_rules! block_while {
     }
 }
 
+/// Turns the non-blocking expression `$e` into a blocking operation that gives up once `$timer`
+/// times out.
+///
+/// This is [`block_while!`] specialized for the common embedded pattern of retrying until a
+/// hardware timer elapses: on every `Error::WouldBlock` this polls `$timer`'s
+/// [`CountDown::wait`]; once that reports the count down is over, this macro gives up, just like
+/// `block_while!` does when its guard becomes `false`.
+///
+/// **NOTE** This macro is feature gated behind the `embedded-hal` Cargo feature.
+///
+/// # Input
+///
+/// An expression `$timer` that implements `embedded_hal::timer::CountDown`
+/// An expression `$e` that evaluates to `nb::Result<T, E>`
+///
+/// # Output
+///
+/// - `Ok(t)` if `$e` evaluates to `Ok(t)`
+/// - `Err(nb::Error::Other(e))` if `$e` evaluates to `Err(nb::Error::Other(e))`
+/// - `Err(Error::WouldBlock)` if `$timer` times out before `$e` stops returning
+///   `Error::WouldBlock`
+///
+/// [`block_while!`]: macro.block_while.html
+/// [`CountDown::wait`]: https://docs.rs/embedded-hal/*/embedded_hal/timer/trait.CountDown.html#tymethod.wait
+#[cfg(feature = "embedded-hal")]
+#[macro_export]
+macro_rules! block_timeout {
+    ($timer:expr, $e:expr) => {
+        loop {
+            #[allow(unreachable_patterns)]
+            match $e {
+                Err($crate::Error::Other(e)) => {
+                    #[allow(unreachable_code)]
+                    break Err($crate::Error::Other(e))
+                },
+                Err($crate::Error::WouldBlock) => {
+                    #[allow(unreachable_patterns)]
+                    match $timer.wait() {
+                        Ok(()) => break Err($crate::Error::WouldBlock),
+                        Err($crate::Error::WouldBlock) => {},
+                        Err($crate::Error::Other(_)) => {},
+                    }
+                },
+                Ok(x) => break Ok(x),
+            }
+        }
+    }
+}
+
 /// Future adapter
 ///
 /// This is a *try* operation from a `nb::Result` to a `futures::Poll`
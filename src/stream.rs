@@ -0,0 +1,59 @@
+//! `futures_core::Stream` adapter for repeated `nb` operations
+//!
+//! This turns a "call this `nb` operation repeatedly, one item per successful call" pattern --
+//! e.g. a `Serial::read` that yields a byte at a time -- into a `Stream`.
+
+use core::pin::Pin;
+use core::task::{Context, Poll};
+
+use futures_core::Stream;
+
+/// Turns a non-blocking expression into a stream that yields one item per successful call
+///
+/// `f` is called on every poll. `Ok(t)` and `Err(nb::Error::Other(e))` each produce one item;
+/// `Err(nb::Error::WouldBlock)` re-arms the waker (so the executor polls again instead of
+/// blocking) and yields `Poll::Pending`.
+///
+/// # Input
+///
+/// A closure `f` that evaluates to `nb::Result<T, E>`
+///
+/// # Output
+///
+/// A stream that yields `Ok(t)` or `Err(e)` every time `f` evaluates to `Ok(t)` or
+/// `Err(nb::Error::Other(e))` respectively
+pub fn poll_fn<T, E, F>(f: F) -> PollFn<F>
+where
+    F: FnMut() -> super::Result<T, E>,
+{
+    PollFn { f }
+}
+
+/// A stream that wraps a non-blocking `FnMut`
+///
+/// This `struct` is created by the [`poll_fn`] function.
+///
+/// [`poll_fn`]: fn.poll_fn.html
+pub struct PollFn<F> {
+    f: F,
+}
+
+impl<F> Unpin for PollFn<F> {}
+
+impl<T, E, F> Stream for PollFn<F>
+where
+    F: FnMut() -> super::Result<T, E>,
+{
+    type Item = Result<T, E>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        match (self.f)() {
+            Ok(x) => Poll::Ready(Some(Ok(x))),
+            Err(super::Error::Other(e)) => Poll::Ready(Some(Err(e))),
+            Err(super::Error::WouldBlock) => {
+                cx.waker().wake_by_ref();
+                Poll::Pending
+            }
+        }
+    }
+}